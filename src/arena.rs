@@ -1,26 +1,198 @@
-/// Wrapper over Vec<T> to store some T and provide access to that data
+/// A generational handle into an [Arena]: a slot index plus the generation that slot
+/// held when this handle was issued.
+///
+/// Comparing generations lets the arena detect use of a handle whose slot has since
+/// been freed and reused, rather than silently reading whatever now lives there.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Index {
+    index: usize,
+    generation: u64,
+}
+
+impl Index {
+    /// The raw slot index, ignoring generation.
+    ///
+    /// Stable across a `reset_to`/`push` cycle that rebuilds the same slots in the same
+    /// order, even though the generation (and so the `Index` itself) changes.
+    pub(crate) fn slot(&self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    generation: u64,
+    /// Monotonic id of the [Arena::push] call that last filled this slot, used by
+    /// [Arena::checkpoint]/[Arena::reset_to] to tell "allocated after the checkpoint"
+    /// apart from "allocated before it but recycled through a lower slot".
+    pushed_at: u64,
+    data: Option<T>,
+}
+
+/// A point in an [Arena]'s push history, recorded by [Arena::checkpoint].
+///
+/// Passing it to [Arena::reset_to] frees every slot `push`ed since, wherever in the
+/// arena that slot physically lives — including ones that were freed and recycled
+/// through a lower index in the meantime. Safe to take repeatedly (e.g. a nested
+/// per-batch checkpoint inside a per-epoch one).
+#[derive(Copy, Clone, Debug)]
+pub struct Checkpoint(u64);
+
+/// Generational-index arena over `T`.
+///
+/// Freed slots are tracked on a free list and reused by later [Arena::push] calls, so a
+/// push/free/push cycle (e.g. a training loop calling [Arena::reset_to] every epoch)
+/// keeps memory flat instead of growing without bound.
 #[derive(Debug)]
 pub struct Arena<T> {
-    nodes: Vec<T>,
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    next_push: u64,
 }
 
 impl<T> Arena<T> {
     pub fn new() -> Self {
         Self {
-            nodes: Vec::default(),
+            slots: Vec::default(),
+            free: Vec::default(),
+            next_push: 0,
+        }
+    }
+
+    pub fn push(&mut self, data: T) -> Index {
+        let pushed_at = self.next_push;
+        self.next_push += 1;
+
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.data = Some(data);
+            slot.pushed_at = pushed_at;
+            Index {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            self.slots.push(Slot {
+                generation: 0,
+                pushed_at,
+                data: Some(data),
+            });
+            Index {
+                index: self.slots.len() - 1,
+                generation: 0,
+            }
+        }
+    }
+
+    pub fn node(&self, id: Index) -> &T {
+        let slot = &self.slots[id.index];
+        assert_eq!(
+            slot.generation, id.generation,
+            "stale arena handle: slot {} has been freed since this handle was issued",
+            id.index
+        );
+        slot.data
+            .as_ref()
+            .expect("stale arena handle: slot has been freed")
+    }
+
+    pub fn node_mut(&mut self, id: Index) -> &mut T {
+        let slot = &mut self.slots[id.index];
+        assert_eq!(
+            slot.generation, id.generation,
+            "stale arena handle: slot {} has been freed since this handle was issued",
+            id.index
+        );
+        slot.data
+            .as_mut()
+            .expect("stale arena handle: slot has been freed")
+    }
+
+    /// Number of slots ever allocated, including freed ones still awaiting reuse.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// The live [Index] currently occupying `slot`, or `None` if it's unallocated or
+    /// has been freed and not yet reused.
+    pub(crate) fn current(&self, slot: usize) -> Option<Index> {
+        let slot_ref = self.slots.get(slot)?;
+        slot_ref.data.as_ref().map(|_| Index {
+            index: slot,
+            generation: slot_ref.generation,
+        })
+    }
+
+    /// Record the current point in the push history, to later [Arena::reset_to].
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.next_push)
+    }
+
+    /// Free every slot `push`ed since `checkpoint`, returning them to the free list for
+    /// reuse by later [Arena::push] calls, wherever in the arena they physically live.
+    pub fn reset_to(&mut self, checkpoint: Checkpoint) {
+        for index in 0..self.slots.len() {
+            let slot = &mut self.slots[index];
+            if slot.pushed_at >= checkpoint.0 && slot.data.take().is_some() {
+                slot.generation += 1;
+                self.free.push(index);
+            }
         }
     }
+}
 
-    pub fn push(&mut self, data: T) -> usize {
-        self.nodes.push(data);
-        self.nodes.len() - 1
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_freed_slots() {
+        let mut a = Arena::new();
+        let ckpt = a.checkpoint();
+
+        let x = a.push(1);
+        a.push(2);
+        assert_eq!(*a.node(x), 1);
+
+        a.reset_to(ckpt);
+        let y = a.push(3);
+        assert_eq!(*a.node(y), 3);
+        assert_eq!(a.len(), 2);
     }
 
-    pub fn node(&self, id: usize) -> &T {
-        &self.nodes[id]
+    #[test]
+    fn nested_checkpoint_after_reset_reclaims_recycled_slots() {
+        let mut a = Arena::new();
+
+        let ckpt1 = a.checkpoint();
+        a.push(1);
+        a.push(2);
+        a.push(3);
+        a.reset_to(ckpt1); // frees slots 0-2, all added to the free list
+
+        let ckpt2 = a.checkpoint();
+        a.push(4); // reuses slot 2
+        a.push(5); // reuses slot 1
+        a.push(6); // reuses slot 0
+        a.push(7); // slot 3, first brand-new slot since ckpt2
+
+        a.reset_to(ckpt2);
+
+        // Every slot pushed since ckpt2 - including the ones recycled through lower
+        // indices - must have been freed, leaving the arena with 4 free slots again.
+        assert_eq!(a.free.len(), 4);
     }
 
-    pub fn node_mut(&mut self, id: usize) -> &mut T {
-        &mut self.nodes[id]
+    #[test]
+    #[should_panic(expected = "stale arena handle")]
+    fn stale_handle_fails_loudly() {
+        let mut a = Arena::new();
+        let ckpt = a.checkpoint();
+
+        let x = a.push(1);
+        a.reset_to(ckpt);
+        a.push(2);
+
+        a.node(x);
     }
 }
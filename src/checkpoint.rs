@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+use crate::nn::{Layer, Neuron, MLP};
+use crate::scalar::ScalarGraph;
+
+/// A serializable snapshot of a single [Neuron]'s weights.
+///
+/// `weights[0]` is the bias, matching the neuron's internal layout.
+#[derive(Serialize, Deserialize)]
+pub struct NeuronCheckpoint {
+    weights: Vec<f64>,
+    relu: bool,
+}
+
+/// A serializable snapshot of a [Layer]'s neurons.
+#[derive(Serialize, Deserialize)]
+pub struct LayerCheckpoint {
+    neurons: Vec<NeuronCheckpoint>,
+}
+
+/// A serializable snapshot of a trained [MLP].
+///
+/// Derives `Serialize`/`Deserialize` so it can be written to and read back from JSON,
+/// independent of the [ScalarGraph] lifetime the original model was trained in.
+#[derive(Serialize, Deserialize)]
+pub struct ModelCheckpoint {
+    layers: Vec<LayerCheckpoint>,
+}
+
+impl MLP<'_> {
+    /// Record this model's current weights, layer shapes, and relu flags.
+    pub fn to_checkpoint(&self) -> ModelCheckpoint {
+        ModelCheckpoint {
+            layers: self
+                .layers()
+                .iter()
+                .map(|l| LayerCheckpoint {
+                    neurons: l
+                        .neurons()
+                        .iter()
+                        .map(|n| NeuronCheckpoint {
+                            weights: n.all_weights().iter().map(|w| w.data()).collect(),
+                            relu: n.relu(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ScalarGraph {
+    /// Reconstruct a fresh [MLP] in this graph whose weights are created directly from
+    /// the values stored in `ckpt`.
+    pub fn load_mlp(&self, ckpt: &ModelCheckpoint) -> MLP {
+        MLP::from_layers(
+            ckpt.layers
+                .iter()
+                .map(|l| {
+                    Layer::from_neurons(
+                        l.neurons
+                            .iter()
+                            .map(|n| Neuron::from_weights(
+                                n.weights.iter().map(|&w| self.scalar(w)).collect(),
+                                n.relu,
+                            ))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json() {
+        let g = ScalarGraph::new();
+        let m = g.mlp(2, &[3, 1], true);
+
+        let ckpt = m.to_checkpoint();
+        let json = serde_json::to_string(&ckpt).unwrap();
+        let restored: ModelCheckpoint = serde_json::from_str(&json).unwrap();
+
+        let g2 = ScalarGraph::new();
+        let m2 = g2.load_mlp(&restored);
+
+        let original_weights: Vec<f64> = m
+            .layers()
+            .iter()
+            .flat_map(|l| l.neurons())
+            .flat_map(|n| n.all_weights().iter().map(|w| w.data()))
+            .collect();
+        let loaded_weights: Vec<f64> = m2
+            .layers()
+            .iter()
+            .flat_map(|l| l.neurons())
+            .flat_map(|n| n.all_weights().iter().map(|w| w.data()))
+            .collect();
+
+        assert_eq!(original_weights, loaded_weights);
+    }
+}
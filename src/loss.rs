@@ -0,0 +1,86 @@
+use crate::scalar::Scalar;
+
+/// Numerically stable softmax over a vector of logits.
+///
+/// Subtracts the max logit before exponentiating to avoid overflow. When `quiet` is
+/// set, adds `1.0` to the (unshifted) denominator, i.e. `exp(x_i) / (1 + Σ exp(x_j))`,
+/// so the resulting probabilities sum to less than
+/// one, letting the model abstain by emitting "no class" when all logits are very
+/// negative. Returns an empty `Vec` for an empty `logits` slice rather than panicking.
+pub fn softmax<'a>(logits: &[Scalar<'a>], quiet: bool) -> Vec<Scalar<'a>> {
+    if logits.is_empty() {
+        return Vec::new();
+    }
+
+    let max = logits
+        .iter()
+        .map(|l| l.data())
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    // In quiet mode the extra `1.0` acts as an implicit logit of 0, so it takes part in
+    // the max; the shift then never overflows `exp` for the added term either.
+    let shift = if quiet { max.max(0.0) } else { max };
+
+    let exps: Vec<Scalar<'a>> = logits.iter().map(|l| (*l - shift).exp()).collect();
+
+    let mut sum = exps.iter().copied().reduce(|a, b| a + b).unwrap();
+    if quiet {
+        sum = sum + (-shift).exp();
+    }
+
+    exps.iter().map(|e| *e / sum).collect()
+}
+
+/// Cross-entropy loss `-log(softmax(logits)[target])` for an integer class `target`.
+///
+/// Built from ordinary [Scalar] ops (including [Scalar::exp] and [Scalar::log]), so gradients flow back through
+/// `logits` via the usual [Scalar::backward] call on the returned loss.
+pub fn cross_entropy<'a>(logits: &[Scalar<'a>], target: usize, quiet: bool) -> Scalar<'a> {
+    -softmax(logits, quiet)[target].log()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::ScalarGraph;
+
+    #[test]
+    fn softmax_sums_to_one() {
+        ScalarGraph::with(|g| {
+            let logits = vec![g.scalar(1.0), g.scalar(2.0), g.scalar(3.0)];
+            let probs = softmax(&logits, false);
+            let sum: f64 = probs.iter().map(|p| p.data()).sum();
+            assert!((sum - 1.0).abs() < 1e-9);
+        });
+    }
+
+    #[test]
+    fn quiet_softmax_sums_to_less_than_one() {
+        ScalarGraph::with(|g| {
+            let logits = vec![g.scalar(1.0), g.scalar(2.0), g.scalar(3.0)];
+            let probs = softmax(&logits, true);
+            let sum: f64 = probs.iter().map(|p| p.data()).sum();
+            assert!(sum < 1.0);
+        });
+    }
+
+    #[test]
+    fn softmax_of_empty_logits_is_empty() {
+        let logits: Vec<Scalar> = Vec::new();
+        assert!(softmax(&logits, false).is_empty());
+        assert!(softmax(&logits, true).is_empty());
+    }
+
+    #[test]
+    fn cross_entropy_backward() {
+        ScalarGraph::with(|g| {
+            let logits = vec![g.scalar(1.0), g.scalar(2.0), g.scalar(3.0)];
+            let loss = cross_entropy(&logits, 2, false);
+            loss.backward();
+            // Gradient on the correct-class logit should be negative (increasing it
+            // decreases the loss), and positive on the others.
+            assert!(logits[2].grad() < 0.0);
+            assert!(logits[0].grad() > 0.0);
+        });
+    }
+}
@@ -3,14 +3,14 @@ use rand::random;
 use crate::scalar::*;
 
 /// Type of output of call() function from [Model] implementors
-enum ModelOutput<'g> {
+pub enum ModelOutput<'g> {
     None,
     Scalar(Scalar<'g>),
     Vector(Vec<Scalar<'g>>),
 }
 
 /// Abstraction for different types made up of [Scalars](Scalar).
-trait Model {
+pub trait Model {
     fn call<'a>(&'a self, x: &[Scalar<'a>]) -> ModelOutput;
     fn parameters(&self) -> Vec<&Scalar>;
     fn zero_grad(&self) {
@@ -73,7 +73,7 @@ pub struct Neuron<'g> {
     relu: bool,
 }
 
-impl Neuron<'_> {
+impl<'g> Neuron<'g> {
     fn w(&self) -> &[Scalar] {
         &self.w[1..]
     }
@@ -81,6 +81,18 @@ impl Neuron<'_> {
     fn b(&self) -> Scalar {
         self.w[0]
     }
+
+    pub(crate) fn all_weights(&self) -> &[Scalar<'g>] {
+        &self.w
+    }
+
+    pub(crate) fn relu(&self) -> bool {
+        self.relu
+    }
+
+    pub(crate) fn from_weights(w: Vec<Scalar<'g>>, relu: bool) -> Self {
+        Self { w, relu }
+    }
 }
 
 impl Model for Neuron<'_> {
@@ -125,6 +137,16 @@ pub struct Layer<'g> {
     neurons: Vec<Neuron<'g>>,
 }
 
+impl<'g> Layer<'g> {
+    pub(crate) fn neurons(&self) -> &[Neuron<'g>] {
+        &self.neurons
+    }
+
+    pub(crate) fn from_neurons(neurons: Vec<Neuron<'g>>) -> Self {
+        Self { neurons }
+    }
+}
+
 impl Model for Layer<'_> {
     fn call<'a>(&'a self, x: &[Scalar<'a>]) -> ModelOutput {
         ModelOutput::Vector(
@@ -162,6 +184,16 @@ pub struct MLP<'g> {
     layers: Vec<Layer<'g>>,
 }
 
+impl<'g> MLP<'g> {
+    pub(crate) fn layers(&self) -> &[Layer<'g>] {
+        &self.layers
+    }
+
+    pub(crate) fn from_layers(layers: Vec<Layer<'g>>) -> Self {
+        Self { layers }
+    }
+}
+
 impl Model for MLP<'_> {
     fn call<'a>(&'a self, x: &[Scalar<'a>]) -> ModelOutput {
         let mut out = ModelOutput::None;
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use crate::arena::Index;
+use crate::nn::Model;
+
+/// Updates a [Model]'s parameters in place from their accumulated gradients.
+///
+/// Call [Model::zero_grad] and `backward()` on the loss before each [Optimizer::step].
+pub trait Optimizer {
+    fn step(&mut self, model: &dyn Model);
+}
+
+/// Stochastic gradient descent with momentum.
+///
+/// Keeps a velocity value per parameter, keyed by the parameter's graph node id.
+///
+/// # Examples
+///
+/// ```
+/// use scalargrad::ScalarGraph;
+/// use scalargrad::optim::{Optimizer, Sgd};
+///
+/// let mut g = ScalarGraph::new();
+/// let n = g.neuron(2, false);
+/// let mut opt = Sgd::new(0.01, 0.9);
+/// opt.step(&n);
+/// ```
+pub struct Sgd {
+    lr: f64,
+    momentum: f64,
+    velocity: HashMap<Index, f64>,
+}
+
+impl Sgd {
+    pub fn new(lr: f64, momentum: f64) -> Self {
+        Self {
+            lr,
+            momentum,
+            velocity: HashMap::new(),
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&mut self, model: &dyn Model) {
+        for p in model.parameters() {
+            let v = self.velocity.entry(p.id()).or_insert(0.0);
+            *v = self.momentum * *v - self.lr * p.grad();
+            p.set_data(p.data() + *v);
+        }
+    }
+}
+
+/// Adam: adaptive moment estimation.
+///
+/// Keeps a first and second moment estimate per parameter, keyed by the parameter's
+/// graph node id, along with a shared step count used for bias correction.
+pub struct Adam {
+    lr: f64,
+    beta1: f64,
+    beta2: f64,
+    eps: f64,
+    t: i32,
+    moments: HashMap<Index, (f64, f64)>,
+}
+
+impl Adam {
+    pub fn new(lr: f64, beta1: f64, beta2: f64, eps: f64) -> Self {
+        Self {
+            lr,
+            beta1,
+            beta2,
+            eps,
+            t: 0,
+            moments: HashMap::new(),
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&mut self, model: &dyn Model) {
+        self.t += 1;
+
+        for p in model.parameters() {
+            let (m, v) = self.moments.entry(p.id()).or_insert((0.0, 0.0));
+            let g = p.grad();
+
+            *m = self.beta1 * *m + (1.0 - self.beta1) * g;
+            *v = self.beta2 * *v + (1.0 - self.beta2) * g * g;
+
+            let m_hat = *m / (1.0 - self.beta1.powi(self.t));
+            let v_hat = *v / (1.0 - self.beta2.powi(self.t));
+
+            p.set_data(p.data() - self.lr * m_hat / (v_hat.sqrt() + self.eps));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar::ScalarGraph;
+
+    #[test]
+    fn sgd_step() {
+        let g = ScalarGraph::new();
+        let n = g.neuron(1, false);
+        n.parameters()[0].set_grad(2.0);
+        let before = n.parameters()[0].data();
+
+        let mut opt = Sgd::new(0.1, 0.0);
+        opt.step(&n);
+
+        assert_eq!(n.parameters()[0].data(), before - 0.1 * 2.0);
+    }
+
+    #[test]
+    fn adam_step() {
+        let g = ScalarGraph::new();
+        let n = g.neuron(1, false);
+        n.parameters()[0].set_data(1.0);
+        n.parameters()[0].set_grad(1.0);
+
+        let mut opt = Adam::new(0.1, 0.9, 0.999, 1e-8);
+        opt.step(&n);
+
+        // After one step with g = 1.0, m = 0.1 and v = 0.001, so the bias-corrected
+        // moments are both 1.0 and the update is lr / (1 + eps).
+        assert!((n.parameters()[0].data() - (1.0 - 0.1 / (1.0_f64.sqrt() + 1e-8))).abs() < 1e-9);
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 use std::ops;
 use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use crate::arena::Arena;
+use crate::arena::{Arena, Checkpoint, Index};
 
 /// An 'arena' to hold all [ScalarData] values of a model as a directed-acyclic graph
 ///
@@ -22,12 +22,26 @@ use crate::arena::Arena;
 /// ```
 pub struct ScalarGraph {
     inner: RwLock<Arena<ScalarData>>,
+    tape: RwLock<Option<Tape>>,
+}
+
+/// A cached reverse-topological order for a given root node, from a prior [Scalar::backward].
+///
+/// Keyed on raw slot indices rather than full [Index]es (which embed a generation that
+/// a `reset_to`/`push` cycle always bumps) so the cache survives the epoch loop
+/// `forward -> backward -> reset_to(checkpoint)` it's meant to speed up, as long as
+/// that loop keeps rebuilding the same slots in the same order. See [ScalarGraph::tape].
+struct Tape {
+    root_slot: usize,
+    order: Vec<usize>,
+    shapes: Vec<OpShape>,
 }
 
 impl ScalarGraph {
     pub fn new() -> Self {
         Self {
             inner: RwLock::new(Arena::new()),
+            tape: RwLock::new(None),
         }
     }
 
@@ -37,6 +51,7 @@ impl ScalarGraph {
     {
         let mut g = Self {
             inner: RwLock::new(Arena::new()),
+            tape: RwLock::new(None),
         };
         f(&mut g)
     }
@@ -49,6 +64,63 @@ impl ScalarGraph {
         self.inner.write().unwrap()
     }
 
+    /// Reverse-topological node order for `root`, reusing the cached tape from a prior
+    /// call when the arena's current contents at the cached slots still line up with
+    /// `root` — which holds across a `reset_to`/`push` cycle that rebuilds the same
+    /// slots in the same order, even though their generations (and so their `Index`es)
+    /// have changed.
+    fn tape(&self, root: Index) -> Vec<Index> {
+        if let Some(cached) = self.replay_tape(root) {
+            return cached;
+        }
+
+        let order = topo(self, root);
+        let shapes = {
+            let con = self.read();
+            order.iter().map(|&id| con.node(id).op.shape()).collect()
+        };
+
+        *self.tape.write().unwrap() = Some(Tape {
+            root_slot: root.slot(),
+            order: order.iter().map(|id| id.slot()).collect(),
+            shapes,
+        });
+        order
+    }
+
+    /// Rebuild the cached tape's `Index`es from the arena's current generations, or
+    /// `None` if the cached slots no longer line up with `root` — either because
+    /// they're not all still live, or because the op each one holds has changed shape
+    /// since caching (e.g. two differently-structured forward passes that happened to
+    /// land their root on the same slot count).
+    fn replay_tape(&self, root: Index) -> Option<Vec<Index>> {
+        let tape = self.tape.read().unwrap();
+        let tape = tape.as_ref()?;
+        if tape.root_slot != root.slot() {
+            return None;
+        }
+
+        let con = self.read();
+        let order: Vec<Index> = tape
+            .order
+            .iter()
+            .map(|&slot| con.current(slot))
+            .collect::<Option<_>>()?;
+
+        if order.last() != Some(&root) {
+            return None;
+        }
+
+        for (&id, &shape) in order.iter().zip(tape.shapes.iter()) {
+            let current_shape = con.node(id).op.shape();
+            if current_shape != shape {
+                return None;
+            }
+        }
+
+        Some(order)
+    }
+
     /// Create a new [Scalar] associated with this graph.
     pub fn scalar(&self, data: f64) -> Scalar {
         let id = self.write().push(ScalarData {
@@ -69,6 +141,20 @@ impl ScalarGraph {
 
         Scalar { id, graph: &self }
     }
+
+    /// Record the current high-water mark (e.g. just after building a model's
+    /// parameters), to later [ScalarGraph::reset_to].
+    pub fn checkpoint(&self) -> Checkpoint {
+        self.read().checkpoint()
+    }
+
+    /// Free every node allocated after `checkpoint`, so the next forward/backward pass
+    /// can reuse their slots instead of growing the arena further.
+    ///
+    /// [Scalar]s created after `checkpoint` must not be used after this call returns.
+    pub fn reset_to(&self, checkpoint: Checkpoint) {
+        self.write().reset_to(checkpoint)
+    }
 }
 
 /// The tier 1 operators for [Scalar].
@@ -77,14 +163,18 @@ impl ScalarGraph {
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum Op {
     None,
-    Add(usize, usize),
-    Mul(usize, usize),
-    Pow(usize, f64),
-    ReLU(usize),
+    Add(Index, Index),
+    Mul(Index, Index),
+    Pow(Index, f64),
+    ReLU(Index),
+    Exp(Index),
+    Log(Index),
+    Tanh(Index),
+    Sigmoid(Index),
 }
 
 impl Op {
-    fn backward(graph: &ScalarGraph, id: usize) {
+    fn backward(graph: &ScalarGraph, id: Index) {
         let mut con = graph.write();
 
         let grad = con.node(id).grad;
@@ -104,9 +194,56 @@ impl Op {
             Op::ReLU(s) => {
                 con.node_mut(s).grad += if con.node(id).data > 0.0 { grad } else { 0.0 };
             }
+            Op::Exp(s) => {
+                let out = con.node(id).data;
+                con.node_mut(s).grad += out * grad;
+            }
+            Op::Log(s) => {
+                con.node_mut(s).grad += (1.0 / con.node(s).data) * grad;
+            }
+            Op::Tanh(s) => {
+                let out = con.node(id).data;
+                con.node_mut(s).grad += (1.0 - out * out) * grad;
+            }
+            Op::Sigmoid(s) => {
+                let out = con.node(id).data;
+                con.node_mut(s).grad += out * (1.0 - out) * grad;
+            }
             Op::None => {}
         }
     }
+
+    /// A structural fingerprint of this op: its kind and its parents' slots (ignoring
+    /// generation, since a `reset_to`/`push` cycle bumps parents' generations too).
+    ///
+    /// Used to verify a cached [Tape] entry still describes the same computation before
+    /// replaying it, rather than just checking its slot is alive.
+    fn shape(&self) -> OpShape {
+        match *self {
+            Op::None => OpShape::None,
+            Op::Add(s, o) => OpShape::Add(s.slot(), o.slot()),
+            Op::Mul(s, o) => OpShape::Mul(s.slot(), o.slot()),
+            Op::Pow(s, e) => OpShape::Pow(s.slot(), e),
+            Op::ReLU(s) => OpShape::ReLU(s.slot()),
+            Op::Exp(s) => OpShape::Exp(s.slot()),
+            Op::Log(s) => OpShape::Log(s.slot()),
+            Op::Tanh(s) => OpShape::Tanh(s.slot()),
+            Op::Sigmoid(s) => OpShape::Sigmoid(s.slot()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum OpShape {
+    None,
+    Add(usize, usize),
+    Mul(usize, usize),
+    Pow(usize, f64),
+    ReLU(usize),
+    Exp(usize),
+    Log(usize),
+    Tanh(usize),
+    Sigmoid(usize),
 }
 
 /// Holds the underlying data for [Scalar] pointers.
@@ -139,7 +276,7 @@ pub(crate) struct ScalarData {
 /// ```
 #[derive(Copy, Clone)]
 pub struct Scalar<'g> {
-    id: usize,
+    id: Index,
     graph: &'g ScalarGraph,
 }
 
@@ -148,11 +285,15 @@ impl Scalar<'_> {
         self.graph.scalar_op(data, op)
     }
 
+    pub(crate) fn id(&self) -> Index {
+        self.id
+    }
+
     pub fn data(&self) -> f64 {
         self.graph.read().node(self.id).data
     }
 
-    fn set_data(&self, data: f64) {
+    pub fn set_data(&self, data: f64) {
         self.graph.write().node_mut(self.id).data = data
     }
 
@@ -175,41 +316,24 @@ impl Scalar<'_> {
         )
     }
 
-    fn topo(&self) -> Vec<usize> {
-        let mut sort = Vec::new();
-        let mut visited = HashSet::new();
-
-        fn dfs(
-            graph: &ScalarGraph,
-            id: usize,
-            sort: &mut Vec<usize>,
-            visited: &mut HashSet<usize>,
-        ) {
-            if !visited.contains(&id) {
-                visited.insert(id);
-                match graph.read().node(id).op {
-                    Op::Add(s, o) => {
-                        dfs(graph, s, sort, visited);
-                        dfs(graph, o, sort, visited);
-                    }
-                    Op::Mul(s, o) => {
-                        dfs(graph, s, sort, visited);
-                        dfs(graph, o, sort, visited);
-                    }
-                    Op::Pow(s, _) => dfs(graph, s, sort, visited),
-                    Op::ReLU(s) => dfs(graph, s, sort, visited),
-                    Op::None => {}
-                }
-                sort.push(id);
-            }
-        }
+    pub fn exp(&self) -> Self {
+        self.with_op(self.data().exp(), Op::Exp(self.id))
+    }
+
+    pub fn log(&self) -> Self {
+        self.with_op(self.data().ln(), Op::Log(self.id))
+    }
 
-        dfs(&self.graph, self.id, &mut sort, &mut visited);
-        sort
+    pub fn tanh(&self) -> Self {
+        self.with_op(self.data().tanh(), Op::Tanh(self.id))
+    }
+
+    pub fn sigmoid(&self) -> Self {
+        self.with_op(1.0 / (1.0 + (-self.data()).exp()), Op::Sigmoid(self.id))
     }
 
     pub fn backward(&self) {
-        let sorted = self.topo();
+        let sorted = self.graph.tape(self.id);
 
         self.set_grad(1.0);
         for id in sorted.iter().rev() {
@@ -218,6 +342,44 @@ impl Scalar<'_> {
     }
 }
 
+/// Reverse-topological sort of the subgraph rooted at `root`, via an explicit work
+/// stack rather than recursion so it doesn't blow the call stack on deep graphs.
+///
+/// Each node is pushed twice: once to queue its parents, once (after they're done) to
+/// record it in `sort`, mirroring the push-after-children order of a recursive DFS.
+fn topo(graph: &ScalarGraph, root: Index) -> Vec<Index> {
+    let mut sort = Vec::new();
+    let mut visited = HashSet::new();
+    let mut stack = vec![(root, false)];
+
+    while let Some((id, expanded)) = stack.pop() {
+        if expanded {
+            sort.push(id);
+            continue;
+        }
+        if !visited.insert(id) {
+            continue;
+        }
+
+        stack.push((id, true));
+        match graph.read().node(id).op {
+            Op::Add(s, o) | Op::Mul(s, o) => {
+                stack.push((o, false));
+                stack.push((s, false));
+            }
+            Op::Pow(s, _)
+            | Op::ReLU(s)
+            | Op::Exp(s)
+            | Op::Log(s)
+            | Op::Tanh(s)
+            | Op::Sigmoid(s) => stack.push((s, false)),
+            Op::None => {}
+        }
+    }
+
+    sort
+}
+
 // ---------------------------------
 // -------------- ADD --------------
 // ---------------------------------
@@ -414,6 +576,121 @@ mod tests {
         });
     }
 
+    #[test]
+    fn exp() {
+        sg::with(|g| {
+            let a = g.scalar(2.0);
+            let b = a.exp();
+            assert_eq!(b.data(), 2.0_f64.exp());
+            b.backward();
+            assert_eq!(a.grad(), 2.0_f64.exp());
+        });
+    }
+
+    #[test]
+    fn log() {
+        sg::with(|g| {
+            let a = g.scalar(2.0);
+            let b = a.log();
+            assert_eq!(b.data(), 2.0_f64.ln());
+            b.backward();
+            assert_eq!(a.grad(), 0.5);
+        });
+    }
+
+    #[test]
+    fn tanh() {
+        sg::with(|g| {
+            let a = g.scalar(0.0);
+            let b = a.tanh();
+            assert_eq!(b.data(), 0.0);
+            b.backward();
+            assert_eq!(a.grad(), 1.0);
+        });
+    }
+
+    #[test]
+    fn sigmoid() {
+        sg::with(|g| {
+            let a = g.scalar(0.0);
+            let b = a.sigmoid();
+            assert_eq!(b.data(), 0.5);
+            b.backward();
+            assert_eq!(a.grad(), 0.25);
+        });
+    }
+
+    #[test]
+    fn reset_to_reuses_nodes() {
+        sg::with(|g| {
+            let a = g.scalar(2.0);
+            let ckpt = g.checkpoint();
+
+            let b = a.pow(2.0);
+            assert_eq!(b.data(), 4.0);
+            g.reset_to(ckpt);
+
+            let c = a.pow(3.0);
+            assert_eq!(c.data(), 8.0);
+            assert_eq!(g.read().len(), 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "stale arena handle")]
+    fn stale_scalar_fails_loudly() {
+        sg::with(|g| {
+            let a = g.scalar(2.0);
+            let ckpt = g.checkpoint();
+
+            let b = a.pow(2.0);
+            g.reset_to(ckpt);
+            a.pow(3.0);
+
+            b.data();
+        });
+    }
+
+    #[test]
+    fn repeated_backward_reuses_cached_tape() {
+        sg::with(|g| {
+            let a = g.scalar(3.0);
+            let b = g.scalar(4.0);
+            let c = a * b;
+
+            c.backward();
+            assert_eq!(a.grad(), 4.0);
+
+            // Structure is unchanged, so this second pass should replay the cached
+            // tape rather than re-sorting; the accumulated grad reflects both passes.
+            assert!(g.replay_tape(c.id).is_some());
+            c.backward();
+            assert_eq!(a.grad(), 8.0);
+        });
+    }
+
+    #[test]
+    fn cached_tape_survives_reset_to() {
+        sg::with(|g| {
+            let a = g.scalar(3.0);
+            let b = g.scalar(4.0);
+            let ckpt = g.checkpoint();
+
+            for _ in 0..3 {
+                let c = a * b;
+                a.set_grad(0.0);
+                b.set_grad(0.0);
+                c.backward();
+                assert_eq!(a.grad(), 4.0);
+                assert_eq!(b.grad(), 3.0);
+                g.reset_to(ckpt);
+            }
+
+            let c = a * b;
+            assert!(g.replay_tape(c.id).is_some());
+        });
+    }
+
     #[test]
     fn backwards() {
         sg::with(|g| {